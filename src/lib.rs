@@ -36,6 +36,18 @@
 //! Besides parsing from a newline-separated file there are also functions for
 //! parsing from various traits, including iterators.
 //!
+//! For free-form input where values are separated by arbitrary runs of
+//! whitespace instead of one-per-line, use the `_words` family instead, e.g.
+//! `from_file_words::<i64>(p)?.sum()`.
+//!
+//! For columnar records, where each line holds several typed fields, use the
+//! `_records` family with a tuple of `Readable` field types, e.g.
+//! `from_file_records::<(i64, i64)>(p)`.
+//!
+//! For streams framed some way other than `\n`, use `from_read_delimited` to
+//! split on an arbitrary byte (e.g. `0` for NUL-separated `find -print0`
+//! output), or `from_read_framed` for length-prefixed records.
+//!
 //! ## Tips
 //!
 //! To convert from an iterator of `Result` to a `Result` of `Vec` use `collect`
@@ -44,7 +56,7 @@
 //! ```rust
 //! use big_s::S;
 //!
-//! let a = vec![Ok(S("0")), Ok(S("1")), Ok(S("2"))];
+//! let a: Vec<Result<String, std::io::Error>> = vec![Ok(S("0")), Ok(S("1")), Ok(S("2"))];
 //! let b: Vec<Result<u32, _>> = parse_list::from_iter(a.into_iter()).collect();
 //! let b: Result<Vec<u32>, _> = b.into_iter().collect();
 //! let b = b.unwrap();
@@ -69,24 +81,43 @@
 //! assert!(b[0] == 0);
 //! assert!(b[1] == 2);
 //! ```
+//!
+//! `from_iter` works over any source error, not just `io::Error` - a
+//! `reqwest::Error`, a database row error, or anything else that's
+//! `Error + Send + Sync + 'static`. For a source that can't fail, like a
+//! plain `Vec<String>`, use `from_infallible_iter` to skip the `Ok(...)`
+//! wrapping entirely:
+//!
+//! ```rust
+//! use big_s::S;
+//!
+//! let a = vec![S("0"), S("1"), S("2")];
+//! let b: Vec<Result<u32, _>> = parse_list::from_infallible_iter(a.into_iter()).collect();
+//! let b: Result<Vec<u32>, _> = b.into_iter().collect();
+//! let b = b.unwrap();
+//!
+//! assert!(b == vec![0, 1, 2]);
+//! ```
 
+use std::collections::VecDeque;
+use std::convert::Infallible;
 use std::marker::PhantomData;
 use std::fmt::{self, Display};
 use std::error::Error;
-use std::iter::{Iterator, Filter};
+use std::iter::{Iterator, Filter, Map};
 use std::fs::File;
 use std::io::{self, Read, BufReader, BufRead, Lines};
 use std::path::Path;
 use std::str::FromStr;
 
-pub fn from_file_lines<T>(p: &Path) -> Result<ParseListIterator<T, Filter<Lines<BufReader<File>>, fn(&Result<String, io::Error>) -> bool>>, io::Error>
+pub fn from_file_lines<T>(p: &Path) -> Result<ParseListIterator<T, io::Error, Filter<Lines<BufReader<File>>, fn(&Result<String, io::Error>) -> bool>>, io::Error>
 where T: FromStr,
       T::Err: Error + Send + Sync + 'static {
     let f = File::open(p)?;
     Ok(from_read_lines(f))
 }
 
-pub fn from_read_lines<T, R>(r: R) -> ParseListIterator<T, Filter<Lines<BufReader<R>>, fn(&Result<String, io::Error>) -> bool>>
+pub fn from_read_lines<T, R>(r: R) -> ParseListIterator<T, io::Error, Filter<Lines<BufReader<R>>, fn(&Result<String, io::Error>) -> bool>>
 where T: FromStr,
       T::Err: Error + Send + Sync + 'static,
       R: Read {
@@ -94,7 +125,7 @@ where T: FromStr,
     from_bufread_lines(r)
 }
 
-pub fn from_bufread_lines<T, B>(b: B) -> ParseListIterator<T, Filter<Lines<B>, fn(&Result<String, io::Error>) -> bool>>
+pub fn from_bufread_lines<T, B>(b: B) -> ParseListIterator<T, io::Error, Filter<Lines<B>, fn(&Result<String, io::Error>) -> bool>>
 where T: FromStr,
       T::Err: Error + Send + Sync + 'static,
       B: BufRead {
@@ -110,36 +141,429 @@ where T: FromStr,
     from_iter(without_blanks)
 }
 
-// TODO: abstract io::Error
+pub fn from_file_words<T>(p: &Path) -> Result<ParseListIterator<T, io::Error, WordsIter<BufReader<File>>>, io::Error>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static {
+    let f = File::open(p)?;
+    Ok(from_read_words(f))
+}
+
+pub fn from_read_words<T, R>(r: R) -> ParseListIterator<T, io::Error, WordsIter<BufReader<R>>>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      R: Read {
+    let r: BufReader<R> = BufReader::new(r);
+    from_bufread_words(r)
+}
+
+pub fn from_bufread_words<T, B>(b: B) -> ParseListIterator<T, io::Error, WordsIter<B>>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      B: BufRead {
+    from_iter(WordsIter {
+        lines: b.lines(),
+        words: VecDeque::new(),
+    })
+}
+
+/// Lazily splits the lines of a `BufRead` into whitespace-delimited words,
+/// pulling one line at a time from the underlying reader rather than
+/// reading the whole stream up front.
+pub struct WordsIter<B>
+where B: BufRead {
+    lines: Lines<B>,
+    words: VecDeque<String>,
+}
+
+impl<B> Iterator for WordsIter<B>
+where B: BufRead {
+    type Item = Result<String, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(w) = self.words.pop_front() {
+                return Some(Ok(w));
+            }
+
+            match self.lines.next() {
+                None => return None,
+                Some(Err(e)) => return Some(Err(e)),
+                Some(Ok(line)) => {
+                    self.words.extend(line.split_whitespace().map(String::from));
+                }
+            }
+        }
+    }
+}
+
+pub fn from_file_records<R>(p: &Path) -> Result<RecordsIter<R, BufReader<File>>, io::Error>
+where R: Readable {
+    let f = File::open(p)?;
+    Ok(from_read_records(f))
+}
+
+pub fn from_read_records<R, Rd>(r: Rd) -> RecordsIter<R, BufReader<Rd>>
+where R: Readable,
+      Rd: Read {
+    let r: BufReader<Rd> = BufReader::new(r);
+    from_bufread_records(r)
+}
+
+pub fn from_bufread_records<R, B>(b: B) -> RecordsIter<R, B>
+where R: Readable,
+      B: BufRead {
+    RecordsIter {
+        lines: b.lines(),
+        marker: PhantomData,
+    }
+}
+
+/// One line or record that parses into a tuple (or struct-like) of several
+/// typed fields, e.g. `x y z` parsing into `(i64, i64, i64)`.
+///
+/// It is implemented for the primitive integer and float types, `bool`,
+/// `char` and `String`, so a single-column record of one of those is just
+/// `T` itself; other `FromStr` types do not implement it. Tuples
+/// `(A, B, ...)` of two to six `Readable` components are implemented by
+/// macro, slicing the token list in order and recursing into each component.
+pub trait Readable {
+    type Output;
+
+    fn words_count() -> usize;
+
+    fn read_words(words: &[&str]) -> Result<Self::Output, String>;
+}
+
+// A blanket `impl<T: FromStr> Readable for T` would conflict with the tuple
+// impls below (the compiler can't rule out some upstream `(A, B): FromStr`),
+// so instead list out the common `FromStr` types one-per-`words_count`-1.
+macro_rules! impl_readable_for_parse {
+    ($($t:ty),+ $(,)?) => {
+        $(
+            impl Readable for $t {
+                type Output = $t;
+
+                fn words_count() -> usize { 1 }
+
+                fn read_words(words: &[&str]) -> Result<Self::Output, String> {
+                    words[0].parse::<$t>().map_err(|e| e.to_string())
+                }
+            }
+        )+
+    };
+}
+
+impl_readable_for_parse!(
+    u8, u16, u32, u64, u128, usize,
+    i8, i16, i32, i64, i128, isize,
+    f32, f64, bool, char, String
+);
+
+/// Marker type whose `Output` is `Vec<char>`, consuming one whitespace token
+/// as its characters. Useful for AoC-style grid puzzles where a line is a row
+/// of single-character cells.
+pub struct Chars;
+
+impl Readable for Chars {
+    type Output = Vec<char>;
+
+    fn words_count() -> usize { 1 }
+
+    fn read_words(words: &[&str]) -> Result<Self::Output, String> {
+        Ok(words[0].chars().collect())
+    }
+}
+
+macro_rules! impl_readable_for_tuple {
+    ($($T:ident => $v:ident),+) => {
+        impl<$($T: Readable),+> Readable for ($($T,)+) {
+            type Output = ($($T::Output,)+);
+
+            fn words_count() -> usize {
+                0 $(+ $T::words_count())+
+            }
+
+            fn read_words(words: &[&str]) -> Result<Self::Output, String> {
+                let mut idx = 0;
+                $(
+                    let count = $T::words_count();
+                    let $v = $T::read_words(&words[idx..idx + count])?;
+                    idx += count;
+                )+
+                let _ = idx;
+                Ok(($($v,)+))
+            }
+        }
+    };
+}
+
+impl_readable_for_tuple!(A => a, B => b);
+impl_readable_for_tuple!(A => a, B => b, C => c);
+impl_readable_for_tuple!(A => a, B => b, C => c, D => d);
+impl_readable_for_tuple!(A => a, B => b, C => c, D => d, E => e);
+impl_readable_for_tuple!(A => a, B => b, C => c, D => d, E => e, F => f);
+
+/// Lazily parses the nonblank lines of a `BufRead` into `R::Output`, one
+/// record per line, checking the whitespace-split word count against
+/// `R::words_count()` before handing the tokens to `R::read_words`.
+pub struct RecordsIter<R, B>
+where R: Readable,
+      B: BufRead {
+    lines: Lines<B>,
+    marker: PhantomData<R>,
+}
+
+impl<R, B> Iterator for RecordsIter<R, B>
+where R: Readable,
+      B: BufRead {
+    type Item = Result<R::Output, ParseListError<io::Error, RecordError>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            match self.lines.next() {
+                None => return None,
+                Some(Err(e)) => return Some(Err(ParseListError::Source(e))),
+                Some(Ok(line)) => {
+                    if line.trim().is_empty() {
+                        continue;
+                    }
+
+                    let words: Vec<&str> = line.split_whitespace().collect();
+                    if words.len() != R::words_count() {
+                        return Some(Err(ParseListError::Parse(RecordError(format!(
+                            "expected {} words, found {} in line: {:?}",
+                            R::words_count(), words.len(), line
+                        )))));
+                    }
+
+                    return Some(R::read_words(&words).map_err(|e| ParseListError::Parse(RecordError(e))));
+                }
+            }
+        }
+    }
+}
+
+/// The error produced when a record's word count doesn't match
+/// `R::words_count()`, or when `R::read_words` itself fails to parse.
+#[derive(Debug)]
+pub struct RecordError(String);
+
+impl Display for RecordError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        Display::fmt(&self.0, f)
+    }
+}
+
+impl Error for RecordError { }
+
+pub fn from_read_delimited<T, R>(r: R, delim: u8) -> ParseListIterator<T, io::Error, DelimitedIter<BufReader<R>>>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      R: Read {
+    from_bufread_delimited(BufReader::new(r), delim)
+}
 
-pub fn from_iter<T, I>(i: I) -> ParseListIterator<T, I>
+pub fn from_bufread_delimited<T, B>(b: B, delim: u8) -> ParseListIterator<T, io::Error, DelimitedIter<B>>
 where T: FromStr,
       T::Err: Error + Send + Sync + 'static,
-      I: Iterator<Item = Result<String, io::Error>> {
-    ParseListIterator::<T, I>(i, PhantomData)
+      B: BufRead {
+    from_iter(DelimitedIter {
+        reader: b,
+        delim,
+        done: false,
+    })
+}
+
+/// Splits a `BufRead` on an arbitrary single-byte terminator instead of
+/// `\n`, e.g. `0` for NUL-separated `find -print0` output. A missing
+/// trailing delimiter on the final record is tolerated: whatever bytes
+/// remain are yielded as the last record.
+pub struct DelimitedIter<B>
+where B: BufRead {
+    reader: B,
+    delim: u8,
+    done: bool,
+}
+
+impl<B> Iterator for DelimitedIter<B>
+where B: BufRead {
+    type Item = Result<String, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut buf = Vec::new();
+        match self.reader.read_until(self.delim, &mut buf) {
+            Ok(0) => {
+                self.done = true;
+                None
+            }
+            Ok(_) => {
+                if buf.last() == Some(&self.delim) {
+                    buf.pop();
+                } else {
+                    // No trailing delimiter: this is the final record.
+                    self.done = true;
+                }
+                Some(String::from_utf8(buf).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+            }
+            Err(e) => {
+                self.done = true;
+                Some(Err(e))
+            }
+        }
+    }
 }
 
-pub struct ParseListIterator<T, I> (I, PhantomData<T>)
+pub fn from_read_framed<T, R>(r: R, sep: u8) -> ParseListIterator<T, io::Error, FramedIter<BufReader<R>>>
 where T: FromStr,
       T::Err: Error + Send + Sync + 'static,
-      I: Iterator<Item = Result<String, io::Error>>;
+      R: Read {
+    from_bufread_framed(BufReader::new(r), sep)
+}
 
-impl<T, I> Iterator for ParseListIterator<T, I>
+pub fn from_bufread_framed<T, B>(b: B, sep: u8) -> ParseListIterator<T, io::Error, FramedIter<B>>
 where T: FromStr,
       T::Err: Error + Send + Sync + 'static,
-      I: Iterator<Item = Result<String, io::Error>>
+      B: BufRead {
+    from_iter(FramedIter {
+        reader: b,
+        sep,
+        done: false,
+    })
+}
+
+/// Reads length-prefixed records: an ASCII decimal byte-length, a single
+/// separator byte, then exactly that many bytes as the record body, before
+/// repeating. Mirrors length-prefixed wire formats; only the next record's
+/// length and body are read off the stream per `next()` call, never the
+/// whole stream up front.
+pub struct FramedIter<B>
+where B: BufRead {
+    reader: B,
+    sep: u8,
+    done: bool,
+}
+
+impl<B> Iterator for FramedIter<B>
+where B: BufRead {
+    type Item = Result<String, io::Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut len_buf = Vec::new();
+        match self.reader.read_until(self.sep, &mut len_buf) {
+            Ok(0) => {
+                self.done = true;
+                return None;
+            }
+            Ok(_) => {}
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        }
+
+        if len_buf.last() == Some(&self.sep) {
+            len_buf.pop();
+        } else {
+            // Hit EOF partway through the length header, with no separator
+            // to close it out: the stream was truncated.
+            self.done = true;
+            return Some(Err(io::Error::new(
+                io::ErrorKind::UnexpectedEof,
+                "stream ended with a truncated record length header",
+            )));
+        }
+
+        let len_str = match std::str::from_utf8(&len_buf) {
+            Ok(s) => s,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(io::Error::new(io::ErrorKind::InvalidData, e)));
+            }
+        };
+
+        let len: usize = match len_str.trim().parse() {
+            Ok(n) => n,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("invalid record length {:?}: {}", len_str, e),
+                )));
+            }
+        };
+
+        let mut body = Vec::new();
+        if body.try_reserve_exact(len).is_err() {
+            self.done = true;
+            return Some(Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("record length {} is too large to allocate", len),
+            )));
+        }
+        body.resize(len, 0);
+        if let Err(e) = self.reader.read_exact(&mut body) {
+            self.done = true;
+            return Some(Err(e));
+        }
+
+        Some(String::from_utf8(body).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e)))
+    }
+}
+
+pub fn from_iter<T, E, I>(i: I) -> ParseListIterator<T, E, I>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      E: Error + Send + Sync + 'static,
+      I: Iterator<Item = Result<String, E>> {
+    ParseListIterator::<T, E, I>(i, PhantomData, PhantomData)
+}
+
+/// Like `from_iter`, but for a source that can't fail, e.g. an in-memory
+/// `Vec<String>`. Drops the `Ok(...)` wrapping that `from_iter` otherwise
+/// requires of every item.
+pub fn from_infallible_iter<T, I>(i: I) -> ParseListIterator<T, Infallible, Map<I, fn(String) -> Result<String, Infallible>>>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      I: Iterator<Item = String> {
+    fn ok(s: String) -> Result<String, Infallible> {
+        Ok(s)
+    }
+
+    from_iter(i.map(ok as fn(String) -> Result<String, Infallible>))
+}
+
+pub struct ParseListIterator<T, E, I> (I, PhantomData<T>, PhantomData<E>)
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      E: Error + Send + Sync + 'static,
+      I: Iterator<Item = Result<String, E>>;
+
+impl<T, E, I> Iterator for ParseListIterator<T, E, I>
+where T: FromStr,
+      T::Err: Error + Send + Sync + 'static,
+      E: Error + Send + Sync + 'static,
+      I: Iterator<Item = Result<String, E>>
 {
 
-    type Item = Result<T, ParseListError<T::Err>>;
+    type Item = Result<T, ParseListError<E, T::Err>>;
 
     fn next(&mut self) -> Option<Self::Item> {
         self.0.next().map(string_result_to_item_result)
     }
 }
 
-fn string_result_to_item_result<T>(v: Result<String, io::Error>) -> Result<T, ParseListError<T::Err>>
+fn string_result_to_item_result<T, E>(v: Result<String, E>) -> Result<T, ParseListError<E, T::Err>>
 where T: FromStr,
-      T::Err: Error + Send + Sync + 'static {
+      T::Err: Error + Send + Sync + 'static,
+      E: Error + Send + Sync + 'static {
     match v {
         Ok(v) => {
             match str::parse(&v) {
@@ -147,25 +571,28 @@ where T: FromStr,
                 Err(e) => Err(ParseListError::Parse(e))
             }
         }
-        Err(e) => Err(ParseListError::Io(e))
+        Err(e) => Err(ParseListError::Source(e))
     }
 }
 
 #[derive(Debug)]
-pub enum ParseListError<TE>
-where TE: Error + Send + Sync + 'static {
-    Io(io::Error),
+pub enum ParseListError<E, TE>
+where E: Error + Send + Sync + 'static,
+      TE: Error + Send + Sync + 'static {
+    Source(E),
     Parse(TE),
 }
 
-impl<TE> Error for ParseListError<TE>
-where TE: Error + Send + Sync + 'static { }
+impl<E, TE> Error for ParseListError<E, TE>
+where E: Error + Send + Sync + 'static,
+      TE: Error + Send + Sync + 'static { }
 
-impl<TE> Display for ParseListError<TE>
-where TE: Error + Send + Sync + 'static {
+impl<E, TE> Display for ParseListError<E, TE>
+where E: Error + Send + Sync + 'static,
+      TE: Error + Send + Sync + 'static {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
-            ParseListError::Io(e) => Display::fmt(e, f),
+            ParseListError::Source(e) => Display::fmt(e, f),
             ParseListError::Parse(e) => Display::fmt(e, f),
         }
     }
@@ -179,7 +606,7 @@ mod tests {
 
     #[test]
     fn from_iter_vec() {
-        let a = vec![Ok(S("0")), Ok(S("1")), Ok(S("2"))];
+        let a: Vec<Result<String, io::Error>> = vec![Ok(S("0")), Ok(S("1")), Ok(S("2"))];
         let b: Vec<Result<u32, _>> = from_iter(a.into_iter()).collect();
         let b: Result<Vec<u32>, _> = b.into_iter().collect();
         let b = b.unwrap();
@@ -192,7 +619,7 @@ mod tests {
         use std::num::ParseIntError;
         let e: io::Error = io::Error::from(io::ErrorKind::NotFound);
         let a: Vec<Result<String, io::Error>> = vec![Ok(S("0")), Err(e), Ok(S("2"))];
-        let b: Vec<Result<u32, ParseListError<ParseIntError>>> = from_iter(a.into_iter()).collect();
+        let b: Vec<Result<u32, ParseListError<io::Error, ParseIntError>>> = from_iter(a.into_iter()).collect();
         assert!(b.len() == 3);
         assert!(b[0].as_ref().unwrap() == &0);
         assert!(b[1].is_err());
@@ -211,6 +638,15 @@ mod tests {
         assert!(b[1] == 2);
     }
 
+    #[test]
+    fn from_infallible_iter_vec() {
+        let a = vec![S("0"), S("1"), S("2")];
+        let b: Vec<Result<u32, _>> = from_infallible_iter(a.into_iter()).collect();
+        let b: Result<Vec<u32>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![0, 1, 2]);
+    }
+
     #[test]
     fn from_bufread_lines_slice() {
         let a = "0\n1\n2".as_bytes();
@@ -332,5 +768,192 @@ mod tests {
         let b = from_file_lines::<u32>(&file_path);
         assert!(b.is_err());
     }
+
+    #[test]
+    fn from_bufread_words_slice() {
+        let a = "0 1\n2  3\t4".as_bytes();
+        let b: Vec<Result<u32, _>> = from_bufread_words(a).collect();
+        let b: Result<Vec<u32>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![0, 1, 2, 3, 4]);
+    }
+
+    #[test]
+    fn from_bufread_words_slice_fail_middle() {
+        let a = "0 boop 2".as_bytes();
+        let b: Vec<Result<u32, _>> = from_bufread_words(a).collect();
+        assert!(b.len() == 3);
+        assert!(b[0].as_ref().unwrap() == &0);
+        assert!(b[1].is_err());
+        assert!(b[2].as_ref().unwrap() == &2);
+    }
+
+    #[test]
+    fn from_read_words_slice() {
+        let a = "0 1 2".as_bytes();
+        let b: Vec<Result<u32, _>> = from_read_words(a).collect();
+        let b: Result<Vec<u32>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn from_file_words_success() {
+        use std::fs;
+        let tmp_dir = TempDir::new("tmp").unwrap();
+        let file_path = tmp_dir.path().join("list");
+        fs::write(&file_path, "3 14 15\n92 6").unwrap();
+
+        let v = from_file_words(&file_path);
+        let v: Vec<Result<i64, _>> = v.unwrap().collect();
+        let v: Result<Vec<i64>, _> = v.into_iter().collect();
+        let v = v.unwrap();
+        assert!(v == vec![3, 14, 15, 92, 6]);
+    }
+
+    #[test]
+    fn from_file_words_not_found() {
+        let tmp_dir = TempDir::new("tmp").unwrap();
+        let file_path = tmp_dir.path().join("list");
+        let b = from_file_words::<u32>(&file_path);
+        assert!(b.is_err());
+    }
+
+    #[test]
+    fn from_bufread_records_pair() {
+        let a = "0 1\n2 3".as_bytes();
+        let b: Vec<Result<(u32, u32), _>> = from_bufread_records::<(u32, u32), _>(a).collect();
+        let b: Result<Vec<(u32, u32)>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![(0, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn from_bufread_records_triple() {
+        let a = "3 14 15\n92 6 5".as_bytes();
+        let b: Vec<Result<(i64, i64, i64), _>> = from_bufread_records::<(i64, i64, i64), _>(a).collect();
+        let b: Result<Vec<(i64, i64, i64)>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![(3, 14, 15), (92, 6, 5)]);
+    }
+
+    #[test]
+    fn from_bufread_records_nested_tuple() {
+        let a = "0 1 2".as_bytes();
+        let b: Vec<Result<(u32, (u32, u32)), _>> = from_bufread_records::<(u32, (u32, u32)), _>(a).collect();
+        let b: Result<Vec<(u32, (u32, u32))>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![(0, (1, 2))]);
+    }
+
+    #[test]
+    fn from_bufread_records_word_count_mismatch() {
+        let a = "0 1 2".as_bytes();
+        let b: Vec<Result<(u32, u32), _>> = from_bufread_records::<(u32, u32), _>(a).collect();
+        assert!(b.len() == 1);
+        assert!(b[0].is_err());
+    }
+
+    #[test]
+    fn from_bufread_records_skips_blank_lines() {
+        let a = "0 1\n\n2 3".as_bytes();
+        let b: Vec<Result<(u32, u32), _>> = from_bufread_records::<(u32, u32), _>(a).collect();
+        let b: Result<Vec<(u32, u32)>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![(0, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn from_bufread_records_chars() {
+        let a = "abc\ndef".as_bytes();
+        let b: Vec<Result<Vec<char>, _>> = from_bufread_records::<Chars, _>(a).collect();
+        let b: Result<Vec<Vec<char>>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![vec!['a', 'b', 'c'], vec!['d', 'e', 'f']]);
+    }
+
+    #[test]
+    fn from_file_records_success() {
+        use std::fs;
+        let tmp_dir = TempDir::new("tmp").unwrap();
+        let file_path = tmp_dir.path().join("list");
+        fs::write(&file_path, "0 1\n2 3").unwrap();
+
+        let v = from_file_records::<(u32, u32)>(&file_path);
+        let v: Vec<Result<(u32, u32), _>> = v.unwrap().collect();
+        let v: Result<Vec<(u32, u32)>, _> = v.into_iter().collect();
+        let v = v.unwrap();
+        assert!(v == vec![(0, 1), (2, 3)]);
+    }
+
+    #[test]
+    fn from_bufread_delimited_nul() {
+        let a = b"zero\x001\x00two".as_slice();
+        let b: Vec<Result<String, _>> = from_bufread_delimited(a, 0).collect();
+        let b: Result<Vec<String>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![S("zero"), S("1"), S("two")]);
+    }
+
+    #[test]
+    fn from_bufread_delimited_no_trailing_delim() {
+        let a = b"0\x001\x002".as_slice();
+        let b: Vec<Result<u32, _>> = from_bufread_delimited(a, 0).collect();
+        let b: Result<Vec<u32>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn from_read_delimited_invalid_utf8() {
+        let a = [0xff, 0x00, b'1'].as_slice();
+        let b: Vec<Result<u32, _>> = from_read_delimited(a, 0).collect();
+        assert!(b.len() == 2);
+        assert!(b[0].is_err());
+        assert!(b[1].as_ref().unwrap() == &1);
+    }
+
+    #[test]
+    fn from_bufread_framed_records() {
+        let a = b"1:02:141:2".as_slice();
+        let b: Vec<Result<String, _>> = from_bufread_framed(a, b':').collect();
+        let b: Result<Vec<String>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![S("0"), S("14"), S("2")]);
+    }
+
+    #[test]
+    fn from_read_framed_ints() {
+        let a = b"1:01:11:2".as_slice();
+        let b: Vec<Result<u32, _>> = from_read_framed(a, b':').collect();
+        let b: Result<Vec<u32>, _> = b.into_iter().collect();
+        let b = b.unwrap();
+        assert!(b == vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn from_bufread_framed_truncated() {
+        let a = b"5:abc".as_slice();
+        let b: Vec<Result<String, _>> = from_bufread_framed(a, b':').collect();
+        assert!(b.len() == 1);
+        assert!(b[0].is_err());
+    }
+
+    #[test]
+    fn from_bufread_framed_truncated_header() {
+        let a = b"1:05".as_slice();
+        let b: Vec<Result<String, _>> = from_bufread_framed(a, b':').collect();
+        assert!(b.len() == 2);
+        assert!(b[0].as_ref().unwrap() == "0");
+        assert!(b[1].is_err());
+    }
+
+    #[test]
+    fn from_bufread_framed_length_too_large() {
+        let header = format!("{}:x", usize::MAX);
+        let b: Vec<Result<String, _>> = from_bufread_framed(header.as_bytes(), b':').collect();
+        assert!(b.len() == 1);
+        assert!(b[0].is_err());
+    }
 }
 